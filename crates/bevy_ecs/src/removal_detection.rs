@@ -2,19 +2,19 @@
 
 use crate::{
     component::{Component, ComponentId, ComponentIdFor, Tick},
-    entity::Entity,
+    entity::{Entity, EntityHashSet},
     event::{Event, EventCursor, EventId, EventIterator, EventIteratorWithId, Events},
     prelude::Local,
     storage::SparseSet,
     system::{ReadOnlySystemParam, SystemMeta, SystemParam},
-    world::{unsafe_world_cell::UnsafeWorldCell, World},
+    world::{unsafe_world_cell::UnsafeWorldCell, FromWorld, World},
 };
 
-use derive_more::derive::Into;
-
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::{
+    any::Any,
     fmt::Debug,
     iter,
     marker::PhantomData,
@@ -22,12 +22,35 @@ use core::{
     option,
 };
 
-/// Wrapper around [`Entity`] for [`RemovedComponents`].
+/// Wrapper around [`Entity`] for [`RemovedComponents`], also recording the [`Tick`] at which
+/// the removal was observed.
 /// Internally, `RemovedComponents` uses these as an `Events<RemovedComponentEntity>`.
-#[derive(Event, Debug, Clone, Into)]
+#[derive(Event, Debug, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 #[cfg_attr(feature = "bevy_reflect", reflect(Debug, Clone))]
-pub struct RemovedComponentEntity(Entity);
+pub struct RemovedComponentEntity {
+    entity: Entity,
+    tick: Tick,
+}
+
+impl RemovedComponentEntity {
+    /// The [`Entity`] that had the component removed, or was despawned with it.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// The [`Tick`] at which this removal was recorded: the tick passed to
+    /// [`RemovedComponentEvents::send`], typically the world's change tick at that time.
+    pub fn tick(&self) -> Tick {
+        self.tick
+    }
+}
+
+impl From<RemovedComponentEntity> for Entity {
+    fn from(value: RemovedComponentEntity) -> Self {
+        value.entity
+    }
+}
 
 /// Wrapper around a [`EventCursor<RemovedComponentEntity>`] so that we
 /// can differentiate events between components.
@@ -66,6 +89,7 @@ impl<T: Component> DerefMut for RemovedComponentReader<T> {
 #[derive(Default, Debug)]
 pub struct RemovedComponentEvents {
     event_sets: SparseSet<ComponentId, Events<RemovedComponentEntity>>,
+    component_data: RemovedComponentDataEvents,
 }
 
 impl RemovedComponentEvents {
@@ -75,11 +99,13 @@ impl RemovedComponentEvents {
     }
 
     /// For each type of component, swaps the event buffers and clears the oldest event buffer.
-    /// In general, this should be called once per frame/update.
+    /// This also updates [`Self::component_data`] in lockstep, so callers only need to call this
+    /// one method (in general, once per frame/update) to keep both in sync.
     pub fn update(&mut self) {
         for (_component_id, events) in self.event_sets.iter_mut() {
             events.update();
         }
+        self.component_data.update();
     }
 
     /// Returns an iterator over components and their entity events.
@@ -95,11 +121,49 @@ impl RemovedComponentEvents {
         self.event_sets.get(component_id.into())
     }
 
-    /// Sends a removal event for the specified component.
-    pub fn send(&mut self, component_id: impl Into<ComponentId>, entity: Entity) {
+    /// The buffered removed component *values*, for components that have opted in via
+    /// [`RemovedComponentDataEvents::register`]. Declaring a [`RemovedComponentData<T>`] system
+    /// param registers `T` automatically.
+    pub fn component_data(&self) -> &RemovedComponentDataEvents {
+        &self.component_data
+    }
+
+    /// Mutable access to [`Self::component_data`], used to register a component so its removed
+    /// values start being buffered.
+    pub fn component_data_mut(&mut self) -> &mut RemovedComponentDataEvents {
+        &mut self.component_data
+    }
+
+    /// Sends a removal event for the specified component, stamped with `tick` (typically the
+    /// world's current change tick).
+    ///
+    /// This is the only signal some removal paths can give — a despawn walking an archetype's
+    /// components by [`ComponentId`], or a type-erased `remove_by_id`, has no concrete `T` to
+    /// hand back. Call sites that *do* have a concrete value in hand should additionally call
+    /// [`Self::send_value`] to also buffer it.
+    pub fn send(&mut self, component_id: impl Into<ComponentId>, entity: Entity, tick: Tick) {
         self.event_sets
             .get_or_insert_with(component_id.into(), Default::default)
-            .write(RemovedComponentEntity(entity));
+            .write(RemovedComponentEntity { entity, tick });
+    }
+
+    /// Buffers `value`, the component's value right before removal, into
+    /// [`Self::component_data`]'s buffer for `T`.
+    ///
+    /// `value` is moved into the buffer if (and only if) `T` was registered via
+    /// [`RemovedComponentDataEvents::register`] — which happens automatically for any system
+    /// that declares a [`RemovedComponentData<T>`] parameter. Otherwise `value` is simply
+    /// dropped here, so components nobody observes this way never pay for the move.
+    ///
+    /// Call this alongside [`Self::send`] wherever a removal path already has the concrete
+    /// value in hand; it does not send the plain entity-only event itself.
+    pub fn send_value<T: Component>(
+        &mut self,
+        component_id: impl Into<ComponentId>,
+        entity: Entity,
+        value: T,
+    ) {
+        self.component_data.send(component_id, entity, value);
     }
 }
 
@@ -109,9 +173,9 @@ impl RemovedComponentEvents {
 /// This acts effectively the same as an [`EventReader`](crate::event::EventReader).
 ///
 /// Note that this does not allow you to see which data existed before removal.
-/// If you need this, you will need to track the component data value on your own,
-/// using a regularly scheduled system that requests `Query<(Entity, &T), Changed<T>>`
-/// and stores the data somewhere safe to later cross-reference.
+/// If you need this, use [`RemovedComponentData<T>`] instead, which buffers the removed value
+/// alongside the entity (declaring it registers `T` with
+/// [`RemovedComponentDataEvents::register`] for you).
 ///
 /// If you are using `bevy_ecs` as a standalone crate,
 /// note that the `RemovedComponents` list will not be automatically cleared for you,
@@ -168,6 +232,18 @@ fn map_id_events(
     (entity.clone().into(), id)
 }
 
+/// Iterator over `(Entity, Tick)` pairs for a specific component's removals.
+///
+/// See [`RemovedComponents::read_with_tick`].
+pub type RemovedIterWithTick<'a> = iter::Map<
+    iter::Flatten<option::IntoIter<iter::Cloned<EventIterator<'a, RemovedComponentEntity>>>>,
+    fn(RemovedComponentEntity) -> (Entity, Tick),
+>;
+
+fn into_entity_tick(event: RemovedComponentEntity) -> (Entity, Tick) {
+    (event.entity, event.tick)
+}
+
 // For all practical purposes, the api surface of `RemovedComponents<T>`
 // should be similar to `EventReader<T>` to reduce confusion.
 impl<'w, 's, T: Component> RemovedComponents<'w, 's, T> {
@@ -222,6 +298,32 @@ impl<'w, 's, T: Component> RemovedComponents<'w, 's, T> {
             .map(map_id_events)
     }
 
+    /// Like [`read`](Self::read), except also returning the [`Tick`] at which each removal was
+    /// recorded.
+    pub fn read_with_tick(&mut self) -> RemovedIterWithTick<'_> {
+        self.reader_mut_with_events()
+            .map(|(reader, events)| reader.read(events).cloned())
+            .into_iter()
+            .flatten()
+            .map(into_entity_tick)
+    }
+
+    /// Iterates over entities whose removal was recorded with a [`Tick`] newer than `last_run`,
+    /// regardless of whether this [`RemovedComponents`] has already consumed them via
+    /// [`read`](Self::read).
+    ///
+    /// This mirrors the semantics `Changed<T>`/`Added<T>` provide for live components, letting
+    /// systems that run irregularly (fixed timestep, conditionally skipped) reason about
+    /// removals relative to their own [`SystemMeta`] last-run tick instead of this reader's
+    /// cursor position.
+    pub fn read_since(&self, last_run: Tick, this_run: Tick) -> impl Iterator<Item = Entity> + '_ {
+        self.events()
+            .into_iter()
+            .flat_map(|events| EventCursor::default().read(events).cloned())
+            .filter(move |event| event.tick.is_newer_than(last_run, this_run))
+            .map(Entity::from)
+    }
+
     /// Determines the number of removal events available to be read from this [`RemovedComponents`] without consuming any.
     pub fn len(&self) -> usize {
         self.events()
@@ -266,3 +368,704 @@ unsafe impl<'a> SystemParam for &'a RemovedComponentEvents {
         world.removed_components()
     }
 }
+
+/// Wrapper around a removed [`Component`]'s last value and the [`Entity`] it was removed from.
+/// Internally, `RemovedComponentData` uses these as an `Events<RemovedComponentValue<T>>`.
+#[derive(Event, Debug, Clone)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Debug, Clone))]
+pub struct RemovedComponentValue<T: Component> {
+    entity: Entity,
+    value: T,
+}
+
+impl<T: Component> RemovedComponentValue<T> {
+    /// The [`Entity`] the component was removed or despawned from.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// The component's value at the moment it was removed.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes this event, returning the entity and its removed component value.
+    pub fn into_parts(self) -> (Entity, T) {
+        (self.entity, self.value)
+    }
+}
+
+/// Wrapper around a [`EventCursor<RemovedComponentValue<T>>`] so that we
+/// can differentiate events between components.
+#[derive(Debug)]
+pub struct RemovedComponentValueReader<T>
+where
+    T: Component,
+{
+    reader: EventCursor<RemovedComponentValue<T>>,
+}
+
+impl<T: Component> Default for RemovedComponentValueReader<T> {
+    fn default() -> Self {
+        Self {
+            reader: Default::default(),
+        }
+    }
+}
+
+impl<T: Component> Deref for RemovedComponentValueReader<T> {
+    type Target = EventCursor<RemovedComponentValue<T>>;
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
+
+impl<T: Component> DerefMut for RemovedComponentValueReader<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.reader
+    }
+}
+
+/// Object-safe handle to a type-erased `Events<RemovedComponentValue<T>>`, so that
+/// [`RemovedComponentDataEvents`] can hold buffers for many different `T` side by side.
+trait AnyRemovedComponentValueEvents: Any + Send + Sync {
+    /// Swaps the event buffers and clears the oldest one. See [`Events::update`].
+    fn update(&mut self);
+
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Component> AnyRemovedComponentValueEvents for Events<RemovedComponentValue<T>> {
+    fn update(&mut self) {
+        Events::update(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Debug for dyn AnyRemovedComponentValueEvents {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnyRemovedComponentValueEvents")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stores the buffered removed [`Component`] values for every component type that has
+/// opted in via [`RemovedComponentDataEvents::register`].
+///
+/// Unlike [`RemovedComponentEvents`], moving a component's value out on every removal has a
+/// clone/move cost that most components shouldn't have to pay, so a component must be
+/// registered before its removals start buffering a value here; until then, removals of that
+/// component are simply not recorded in this store (though they still show up in
+/// [`RemovedComponentEvents`]).
+#[derive(Default, Debug)]
+pub struct RemovedComponentDataEvents {
+    event_sets: SparseSet<ComponentId, Box<dyn AnyRemovedComponentValueEvents>>,
+}
+
+impl RemovedComponentDataEvents {
+    /// Creates an empty storage buffer for removed component values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// For each registered type of component, swaps the event buffers and clears the oldest
+    /// event buffer. This must be called in lockstep with [`RemovedComponentEvents::update`],
+    /// in general once per frame/update.
+    pub fn update(&mut self) {
+        for (_component_id, events) in self.event_sets.iter_mut() {
+            events.update();
+        }
+    }
+
+    /// Opts `component_id` in to having its removed values buffered here. Calling this more
+    /// than once for the same component is a no-op.
+    pub fn register<T: Component>(&mut self, component_id: impl Into<ComponentId>) {
+        self.event_sets
+            .get_or_insert_with(component_id.into(), || {
+                Box::new(Events::<RemovedComponentValue<T>>::default())
+            });
+    }
+
+    /// Returns `true` if `component_id` has been registered via [`Self::register`].
+    pub fn is_registered(&self, component_id: impl Into<ComponentId>) -> bool {
+        self.event_sets.get(component_id.into()).is_some()
+    }
+
+    /// Gets the event storage for a given component, if it has been registered.
+    pub fn get<T: Component>(
+        &self,
+        component_id: impl Into<ComponentId>,
+    ) -> Option<&Events<RemovedComponentValue<T>>> {
+        self.event_sets
+            .get(component_id.into())?
+            .as_any()
+            .downcast_ref()
+    }
+
+    /// Buffers `value` as having been removed from `entity`, if `component_id` was registered.
+    /// No-ops (and drops `value`) if the component was never registered, so unregistered
+    /// components don't pay even the cost of a lookup miss turning into allocation.
+    pub fn send<T: Component>(
+        &mut self,
+        component_id: impl Into<ComponentId>,
+        entity: Entity,
+        value: T,
+    ) {
+        let Some(events) = self.event_sets.get_mut(component_id.into()) else {
+            return;
+        };
+        let Some(events) = events
+            .as_any_mut()
+            .downcast_mut::<Events<RemovedComponentValue<T>>>()
+        else {
+            return;
+        };
+        events.write(RemovedComponentValue { entity, value });
+    }
+}
+
+/// Iterator over `(Entity, &T)` pairs for a specific component's removals.
+///
+/// See [`RemovedComponentData`].
+pub type RemovedDataIter<'a, T> = iter::Map<
+    iter::Flatten<option::IntoIter<EventIterator<'a, RemovedComponentValue<T>>>>,
+    fn(&RemovedComponentValue<T>) -> (Entity, &T),
+>;
+
+fn into_entity_value<T: Component>(event: &RemovedComponentValue<T>) -> (Entity, &T) {
+    (event.entity(), event.value())
+}
+
+/// [`SystemParam::State`] for [`RemovedComponentData`]: `T`'s resolved [`ComponentId`] plus this
+/// system's own removal-value reader.
+#[doc(hidden)]
+pub struct RemovedComponentDataState<T: Component> {
+    component_id: ComponentId,
+    reader: RemovedComponentValueReader<T>,
+}
+
+/// A [`SystemParam`] that yields the `(Entity, &T)` pairs of `T` [`Component`] values that were
+/// removed or despawned.
+///
+/// This is the value-capturing sibling of [`RemovedComponents`]: where `RemovedComponents<T>`
+/// only tells you *which* entity lost its `T`, `RemovedComponentData<T>` also hands back the
+/// `T` it had right before removal.
+///
+/// Declaring this parameter automatically registers `T` with
+/// [`RemovedComponentDataEvents::register`] the first time a system using it is initialized,
+/// opting `T` in to having its value moved (not cloned) into a buffer from then on;
+/// components no system ever reads this way never pay that cost.
+///
+/// Only removals whose value is passed to [`RemovedComponentEvents::send_value`] are buffered;
+/// a removal reported through [`RemovedComponentEvents::send`] alone yields no value here.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::removal_detection::RemovedComponentData;
+/// #
+/// # #[derive(Component)]
+/// # struct MyComponent(u32);
+/// fn react_on_removal(mut removed: RemovedComponentData<MyComponent>) {
+///     for (entity, value) in removed.read() {
+///         println!("{entity} used to be {}", value.0);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(react_on_removal);
+/// ```
+pub struct RemovedComponentData<'w, 's, T: Component> {
+    state: &'s mut RemovedComponentDataState<T>,
+    event_sets: &'w RemovedComponentEvents,
+}
+
+impl<'w, 's, T: Component> RemovedComponentData<'w, 's, T> {
+    /// Fetch underlying [`Events`].
+    pub fn events(&self) -> Option<&Events<RemovedComponentValue<T>>> {
+        self.event_sets.component_data().get::<T>(self.state.component_id)
+    }
+
+    /// Iterates over the `(Entity, &T)` pairs this [`RemovedComponentData`] has not seen yet.
+    /// This updates the event counter, which means subsequent reads will not include events
+    /// that happened before now.
+    pub fn read(&mut self) -> RemovedDataIter<'_, T> {
+        let events = self.event_sets.component_data().get::<T>(self.state.component_id);
+        events
+            .map(|events| self.state.reader.read(events))
+            .into_iter()
+            .flatten()
+            .map(into_entity_value as fn(&RemovedComponentValue<T>) -> (Entity, &T))
+    }
+
+    /// Determines the number of removal values available to be read without consuming any.
+    pub fn len(&self) -> usize {
+        self.events()
+            .map(|events| self.state.reader.len(events))
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if there are no removal values available to read.
+    pub fn is_empty(&self) -> bool {
+        self.events()
+            .is_none_or(|events| self.state.reader.is_empty(events))
+    }
+
+    /// Consumes all available events.
+    pub fn clear(&mut self) {
+        if let Some(events) = self.events() {
+            self.state.reader.clear(events);
+        }
+    }
+}
+
+// SAFETY: Only reads world-level removed-component-value storage and this system's own local
+// reader state; performs no component access.
+unsafe impl<'w, 's, T: Component> ReadOnlySystemParam for RemovedComponentData<'w, 's, T> {}
+
+// SAFETY: see above. `init_state` only registers `T` in the world's removal-value buffer; it
+// does not read or write any component data.
+unsafe impl<'w, 's, T: Component> SystemParam for RemovedComponentData<'w, 's, T> {
+    type State = RemovedComponentDataState<T>;
+    type Item<'wi, 'si> = RemovedComponentData<'wi, 'si, T>;
+
+    fn init_state(world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {
+        let component_id = world.register_component::<T>();
+        world
+            .removed_components_mut()
+            .component_data_mut()
+            .register::<T>(component_id);
+        RemovedComponentDataState {
+            component_id,
+            reader: RemovedComponentValueReader::default(),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_param<'wi, 'si>(
+        state: &'si mut Self::State,
+        _system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'wi>,
+        _change_tick: Tick,
+    ) -> Self::Item<'wi, 'si> {
+        RemovedComponentData {
+            state,
+            event_sets: world.removed_components(),
+        }
+    }
+}
+
+// SAFETY: Only reads World removed component values
+unsafe impl<'a> ReadOnlySystemParam for &'a RemovedComponentDataEvents {}
+
+// SAFETY: no component value access.
+unsafe impl<'a> SystemParam for &'a RemovedComponentDataEvents {
+    type State = ();
+    type Item<'w, 's> = &'w RemovedComponentDataEvents;
+
+    fn init_state(_world: &mut World, _system_meta: &mut SystemMeta) -> Self::State {}
+
+    #[inline]
+    unsafe fn get_param<'w, 's>(
+        _state: &'s mut Self::State,
+        _system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        _change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        world.removed_components().component_data()
+    }
+}
+
+/// A set of [`Component`] types whose removals [`RemovedComponentsAny`] and
+/// [`RemovedComponentsAll`] can read from a single `SystemParam`.
+///
+/// Implemented for a single [`Component`] and for tuples of components up to four elements;
+/// you should not need to implement this yourself.
+pub trait RemovedComponentSet: Send + Sync + 'static {
+    /// Number of components tracked by this set.
+    const LEN: usize;
+
+    /// Registers every component in this set and returns their [`ComponentId`]s, in order.
+    fn component_ids(world: &mut World) -> Vec<ComponentId>;
+}
+
+impl<T: Component> RemovedComponentSet for T {
+    const LEN: usize = 1;
+
+    fn component_ids(world: &mut World) -> Vec<ComponentId> {
+        vec![world.register_component::<T>()]
+    }
+}
+
+macro_rules! impl_removed_component_set_for_tuple {
+    ($len:expr, $($T:ident),+) => {
+        impl<$($T: Component),+> RemovedComponentSet for ($($T,)+) {
+            const LEN: usize = $len;
+
+            fn component_ids(world: &mut World) -> Vec<ComponentId> {
+                vec![$(world.register_component::<$T>()),+]
+            }
+        }
+    };
+}
+
+impl_removed_component_set_for_tuple!(2, A, B);
+impl_removed_component_set_for_tuple!(3, A, B, C);
+impl_removed_component_set_for_tuple!(4, A, B, C, D);
+
+/// Per-system cached [`ComponentId`]s for a [`RemovedComponentSet`], resolved once (the same
+/// way [`ComponentIdFor`] resolves a single component's id) and reused across calls.
+#[derive(Debug)]
+pub struct RemovedComponentSetIds<S: RemovedComponentSet>(Vec<ComponentId>, PhantomData<S>);
+
+impl<S: RemovedComponentSet> FromWorld for RemovedComponentSetIds<S> {
+    fn from_world(world: &mut World) -> Self {
+        Self(S::component_ids(world), PhantomData)
+    }
+}
+
+impl<S: RemovedComponentSet> Deref for RemovedComponentSetIds<S> {
+    type Target = [ComponentId];
+    fn deref(&self) -> &[ComponentId] {
+        &self.0
+    }
+}
+
+/// One [`EventCursor<RemovedComponentEntity>`] per component tracked by a [`RemovedComponentSet`].
+#[derive(Debug)]
+pub struct RemovedComponentSetReaders<S: RemovedComponentSet>(
+    Vec<EventCursor<RemovedComponentEntity>>,
+    PhantomData<S>,
+);
+
+impl<S: RemovedComponentSet> Default for RemovedComponentSetReaders<S> {
+    fn default() -> Self {
+        Self(
+            (0..S::LEN).map(|_| EventCursor::default()).collect(),
+            PhantomData,
+        )
+    }
+}
+
+impl<S: RemovedComponentSet> Deref for RemovedComponentSetReaders<S> {
+    type Target = [EventCursor<RemovedComponentEntity>];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<S: RemovedComponentSet> DerefMut for RemovedComponentSetReaders<S> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// A [`SystemParam`] that reads removal events for several component types at once, yielding
+/// the entities that lost *any* of them this update window, deduplicated.
+///
+/// This replaces the boilerplate of declaring one [`RemovedComponents<T>`] per tracked
+/// component and manually merging the resulting entity sets.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::removal_detection::RemovedComponentsAny;
+/// #
+/// # #[derive(Component)]
+/// # struct A;
+/// # #[derive(Component)]
+/// # struct B;
+/// fn react_on_removal(mut removed: RemovedComponentsAny<(A, B)>) {
+///     for entity in removed.read() {
+///         println!("{entity} lost A, B, or both");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(react_on_removal);
+/// ```
+#[derive(SystemParam)]
+pub struct RemovedComponentsAny<'w, 's, S: RemovedComponentSet> {
+    component_ids: Local<'s, RemovedComponentSetIds<S>>,
+    readers: Local<'s, RemovedComponentSetReaders<S>>,
+    event_sets: &'w RemovedComponentEvents,
+}
+
+impl<'w, 's, S: RemovedComponentSet> RemovedComponentsAny<'w, 's, S> {
+    /// Iterates over the entities that lost any of `S`'s components since this was last read,
+    /// with duplicates (an entity that lost more than one tracked component) collapsed.
+    pub fn read(&mut self) -> impl Iterator<Item = Entity> + '_ {
+        let mut seen = EntityHashSet::default();
+        for (component_id, reader) in self.component_ids.iter().zip(self.readers.iter_mut()) {
+            if let Some(events) = self.event_sets.get(*component_id) {
+                seen.extend(reader.read(events).cloned().map(Into::into));
+            }
+        }
+        seen.into_iter()
+    }
+}
+
+/// A [`SystemParam`] that reads removal events for several component types at once, yielding
+/// only the entities that lost *all* of them during the same update window.
+///
+/// This replaces the boilerplate of declaring one [`RemovedComponents<T>`] per tracked
+/// component and manually intersecting the resulting entity sets.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::system::IntoSystem;
+/// # use bevy_ecs::removal_detection::RemovedComponentsAll;
+/// #
+/// # #[derive(Component)]
+/// # struct A;
+/// # #[derive(Component)]
+/// # struct B;
+/// fn react_on_removal(mut removed: RemovedComponentsAll<(A, B)>) {
+///     for entity in removed.read() {
+///         println!("{entity} lost both A and B");
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(react_on_removal);
+/// ```
+#[derive(SystemParam)]
+pub struct RemovedComponentsAll<'w, 's, S: RemovedComponentSet> {
+    component_ids: Local<'s, RemovedComponentSetIds<S>>,
+    readers: Local<'s, RemovedComponentSetReaders<S>>,
+    event_sets: &'w RemovedComponentEvents,
+}
+
+impl<'w, 's, S: RemovedComponentSet> RemovedComponentsAll<'w, 's, S> {
+    /// Iterates over the entities that lost every one of `S`'s components since this was last
+    /// read, in the same update window.
+    pub fn read(&mut self) -> impl Iterator<Item = Entity> + '_ {
+        let mut ids = self.component_ids.iter().zip(self.readers.iter_mut());
+        let mut result = match ids.next() {
+            Some((component_id, reader)) => match self.event_sets.get(*component_id) {
+                Some(events) => reader.read(events).cloned().map(Into::into).collect(),
+                None => EntityHashSet::default(),
+            },
+            None => EntityHashSet::default(),
+        };
+        for (component_id, reader) in ids {
+            let this: EntityHashSet = match self.event_sets.get(*component_id) {
+                Some(events) => reader.read(events).cloned().map(Into::into).collect(),
+                None => EntityHashSet::default(),
+            };
+            result.retain(|entity| this.contains(entity));
+        }
+        result.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{self as bevy_ecs, component::Component, system::SystemState};
+
+    #[derive(Component)]
+    struct A(u32);
+
+    #[derive(Component)]
+    struct B(u32);
+
+    #[test]
+    fn removed_component_data_captures_value() {
+        let mut world = World::new();
+        let component_id = world.register_component::<A>();
+        world
+            .removed_components_mut()
+            .component_data_mut()
+            .register::<A>(component_id);
+
+        let entity = Entity::from_raw(0);
+        world
+            .removed_components_mut()
+            .send(component_id, entity, Tick::new(1));
+        world
+            .removed_components_mut()
+            .send_value::<A>(component_id, entity, A(42));
+
+        let events = world
+            .removed_components()
+            .component_data()
+            .get::<A>(component_id)
+            .expect("A was registered, so its removal should have been buffered");
+        let (got_entity, got_value) = EventCursor::default()
+            .read(events)
+            .map(into_entity_value::<A>)
+            .next()
+            .expect("one removal was sent");
+        assert_eq!(got_entity, entity);
+        assert_eq!(got_value.0, 42);
+    }
+
+    #[test]
+    fn removed_component_data_ignores_unregistered_components() {
+        let mut world = World::new();
+        let component_id = world.register_component::<A>();
+
+        world
+            .removed_components_mut()
+            .send_value::<A>(component_id, Entity::from_raw(0), A(1));
+
+        assert!(world
+            .removed_components()
+            .component_data()
+            .get::<A>(component_id)
+            .is_none());
+    }
+
+    #[test]
+    fn removed_component_data_via_real_removal_path() {
+        let mut world = World::new();
+        let component_id = world.register_component::<A>();
+        world
+            .removed_components_mut()
+            .component_data_mut()
+            .register::<A>(component_id);
+
+        let entity = world.spawn(A(7)).id();
+        world.entity_mut(entity).remove::<A>();
+
+        // `EntityWorldMut::remove` drives the real removal path (implemented in
+        // `world/entity_ref.rs`, outside this file), which calls `RemovedComponentEvents::send`
+        // and, for a registered component, `send_value` with the component's last value.
+        let events = world
+            .removed_components()
+            .component_data()
+            .get::<A>(component_id)
+            .expect("A was registered, so its removal should have been buffered");
+        let (got_entity, got_value) = EventCursor::default()
+            .read(events)
+            .map(into_entity_value::<A>)
+            .next()
+            .expect("removing A should have recorded its value");
+        assert_eq!(got_entity, entity);
+        assert_eq!(got_value.0, 7);
+    }
+
+    #[test]
+    fn removed_components_any_deduplicates_entities() {
+        let mut world = World::new();
+        let mut state = SystemState::<RemovedComponentsAny<(A, B)>>::new(&mut world);
+        let a_id = world.register_component::<A>();
+        let b_id = world.register_component::<B>();
+
+        let lost_both = Entity::from_raw(0);
+        let lost_a_only = Entity::from_raw(1);
+        let tick = world.change_tick();
+        world.removed_components_mut().send(a_id, lost_both, tick);
+        world.removed_components_mut().send(b_id, lost_both, tick);
+        world
+            .removed_components_mut()
+            .send(a_id, lost_a_only, tick);
+
+        let mut removed = state.get_mut(&mut world);
+        let mut read: Vec<_> = removed.read().collect();
+        read.sort();
+        assert_eq!(read, vec![lost_both, lost_a_only]);
+    }
+
+    #[test]
+    fn removed_components_all_requires_every_component() {
+        let mut world = World::new();
+        let mut state = SystemState::<RemovedComponentsAll<(A, B)>>::new(&mut world);
+        let a_id = world.register_component::<A>();
+        let b_id = world.register_component::<B>();
+
+        let lost_both = Entity::from_raw(0);
+        let lost_a_only = Entity::from_raw(1);
+        let tick = world.change_tick();
+        world.removed_components_mut().send(a_id, lost_both, tick);
+        world.removed_components_mut().send(b_id, lost_both, tick);
+        world
+            .removed_components_mut()
+            .send(a_id, lost_a_only, tick);
+
+        let mut removed = state.get_mut(&mut world);
+        let read: Vec<_> = removed.read().collect();
+        assert_eq!(read, vec![lost_both]);
+    }
+
+    #[test]
+    fn removed_components_any_all_via_real_despawn() {
+        let mut world = World::new();
+        let mut any_state = SystemState::<RemovedComponentsAny<(A, B)>>::new(&mut world);
+        let mut all_state = SystemState::<RemovedComponentsAll<(A, B)>>::new(&mut world);
+
+        let entity = world.spawn((A(0), B(0))).id();
+        world.despawn(entity);
+
+        // Despawning walks the entity's archetype by `ComponentId` and reports each component's
+        // removal (implemented in `world/entity_ref.rs`, outside this file), so both A and B
+        // show up as lost by this entity.
+        let any: Vec<_> = any_state.get_mut(&mut world).read().collect();
+        assert_eq!(any, vec![entity]);
+        let all: Vec<_> = all_state.get_mut(&mut world).read().collect();
+        assert_eq!(all, vec![entity]);
+    }
+
+    #[test]
+    fn removed_components_read_with_tick_and_read_since() {
+        let mut world = World::new();
+        let mut state = SystemState::<RemovedComponents<A>>::new(&mut world);
+        let a_id = world.register_component::<A>();
+        let entity = Entity::from_raw(0);
+
+        let removal_tick = world.change_tick();
+        world
+            .removed_components_mut()
+            .send(a_id, entity, removal_tick);
+        world.increment_change_tick();
+        let this_run = world.change_tick();
+
+        let mut removed = state.get_mut(&mut world);
+        let (got_entity, got_tick) = removed
+            .read_with_tick()
+            .next()
+            .expect("one removal was recorded");
+        assert_eq!(got_entity, entity);
+        assert_eq!(got_tick, removal_tick);
+
+        // Newer than a tick from before the removal: picked up.
+        assert_eq!(removed.read_since(Tick::new(0), this_run).count(), 1);
+        // Newer than the removal's own tick: not newer, so filtered out.
+        assert_eq!(removed.read_since(removal_tick, this_run).count(), 0);
+    }
+
+    #[test]
+    fn removed_components_tick_via_real_removal_path() {
+        let mut world = World::new();
+        let mut state = SystemState::<RemovedComponents<A>>::new(&mut world);
+
+        let entity = world.spawn(A(0)).id();
+        let removal_tick = world.change_tick();
+        world.entity_mut(entity).remove::<A>();
+        world.increment_change_tick();
+        let this_run = world.change_tick();
+
+        // `EntityWorldMut::remove` stamps the removal with the world's change tick at the time
+        // of removal (implemented in `world/entity_ref.rs`, outside this file).
+        let mut removed = state.get_mut(&mut world);
+        let (got_entity, got_tick) = removed
+            .read_with_tick()
+            .next()
+            .expect("removing A should have recorded a removal");
+        assert_eq!(got_entity, entity);
+        assert_eq!(got_tick, removal_tick);
+        assert_eq!(removed.read_since(removal_tick, this_run).count(), 0);
+    }
+}